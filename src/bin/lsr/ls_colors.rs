@@ -0,0 +1,72 @@
+use std::collections::HashMap;
+use std::env;
+
+/// A parsed `LS_COLORS` environment variable: a map from lowercase
+/// extension to its ANSI SGR code, plus the handful of type-level codes
+/// (`di`, `ln`, `ex`) it defines alongside extensions.
+pub struct LsColors {
+    by_ext: HashMap<String, String>,
+    directory: Option<String>,
+    symlink: Option<String>,
+    executable: Option<String>,
+}
+
+impl LsColors {
+    pub fn from_env() -> Self {
+        Self::parse(&env::var("LS_COLORS").unwrap_or_default())
+    }
+
+    fn parse(raw: &str) -> Self {
+        let mut by_ext = HashMap::new();
+        let mut directory = None;
+        let mut symlink = None;
+        let mut executable = None;
+
+        for entry in raw.split(':') {
+            let Some((key, value)) = entry.split_once('=') else {
+                continue;
+            };
+
+            if let Some(ext) = key.strip_prefix("*.") {
+                by_ext.insert(ext.to_lowercase(), value.to_string());
+                continue;
+            }
+
+            match key {
+                "di" => directory = Some(value.to_string()),
+                "ln" => symlink = Some(value.to_string()),
+                "ex" => executable = Some(value.to_string()),
+                _ => {}
+            }
+        }
+
+        Self {
+            by_ext,
+            directory,
+            symlink,
+            executable,
+        }
+    }
+
+    pub fn color_for_ext(&self, ext: &str) -> Option<&str> {
+        self.by_ext.get(ext).map(String::as_str)
+    }
+
+    pub fn directory(&self) -> Option<&str> {
+        self.directory.as_deref()
+    }
+
+    pub fn symlink(&self) -> Option<&str> {
+        self.symlink.as_deref()
+    }
+
+    pub fn executable(&self) -> Option<&str> {
+        self.executable.as_deref()
+    }
+}
+
+/// Wraps `text` in the raw ANSI SGR escape for `code`, as used by
+/// `LS_COLORS` values (e.g. `01;34`).
+pub fn paint(code: &str, text: &str) -> String {
+    format!("\x1b[{}m{}\x1b[0m", code, text)
+}
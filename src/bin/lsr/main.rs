@@ -0,0 +1,656 @@
+mod ls_colors;
+
+use clap::{ArgGroup, Parser, ValueEnum};
+use colored::Colorize;
+use globset::{Glob, GlobSet, GlobSetBuilder};
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
+use ls_colors::LsColors;
+use rayon::prelude::*;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use terminal_size::{terminal_size, Width};
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+const BYTES_IN_KB: f64 = 1024.0;
+const BYTES_IN_MB: f64 = BYTES_IN_KB * 1024.0;
+const BYTES_IN_GB: f64 = BYTES_IN_MB * 1024.0;
+
+#[derive(Parser, Debug)]
+#[command(about, long_about = None)]
+#[clap(name = "lsr")]
+#[clap(group(ArgGroup::new("units").args(["unit", "binary", "si", "raw_bytes"])))]
+struct Cli {
+    /// Path to directory
+    #[clap(value_parser, default_value = ".")]
+    location: String,
+
+    /// Recursive depth for listing of sub directories.
+    /// Negative value for no limit
+    #[clap(short, long, default_value_t = -1, verbatim_doc_comment)]
+    depth: i8,
+
+    /// Show hidden files
+    #[clap(short, long, action)]
+    all: bool,
+
+    /// Aggregate files smaller than this size into a single summary line
+    /// per directory. Accepts a plain byte count or a suffixed value such
+    /// as `1M`, `512K` or `2G`
+    #[clap(long, value_parser = parse_size, verbatim_doc_comment)]
+    aggr: Option<u64>,
+
+    /// Draw a proportional usage bar next to each entry, showing its share
+    /// of the containing directory's total size
+    #[clap(long, action)]
+    bars: bool,
+
+    /// Sort entries by name, size or extension
+    #[clap(long, value_enum)]
+    sort: Option<SortKey>,
+
+    /// Reverse the sort order
+    #[clap(long, action)]
+    reverse: bool,
+
+    /// List directories before files
+    #[clap(long, action)]
+    dirs_first: bool,
+
+    /// Report real disk usage (allocated blocks) instead of apparent file
+    /// size. Falls back to apparent size on platforms without block info
+    #[clap(short, long, action, verbatim_doc_comment)]
+    usage: bool,
+
+    /// Plain ASCII output: replace the tree glyphs with `|`/`` ` ``/`+`/`-`
+    /// and disable all color
+    #[clap(long, action, verbatim_doc_comment)]
+    ascii: bool,
+
+    /// Unit base used to format sizes
+    #[clap(long, value_enum)]
+    unit: Option<Unit>,
+
+    /// Use binary units (KiB/MiB/GiB, base 1024). Shorthand for --unit binary
+    #[clap(short = '2', action, verbatim_doc_comment)]
+    binary: bool,
+
+    /// Use SI units (KB/MB/GB, base 1000). Shorthand for --unit si
+    #[clap(short = '0', action, verbatim_doc_comment)]
+    si: bool,
+
+    /// Show raw byte counts. Shorthand for --unit bytes
+    #[clap(short = 'b', action, verbatim_doc_comment)]
+    raw_bytes: bool,
+
+    /// Exclude entries whose name or path matches this glob. Repeatable
+    #[clap(long = "exclude", verbatim_doc_comment)]
+    excludes: Vec<String>,
+
+    /// Skip entries ignored by .gitignore files encountered while descending
+    #[clap(long, action)]
+    gitignore: bool,
+}
+
+fn build_exclude_set(patterns: &[String]) -> Result<GlobSet, globset::Error> {
+    let mut builder = GlobSetBuilder::new();
+    for pattern in patterns {
+        builder.add(Glob::new(pattern)?);
+    }
+    builder.build()
+}
+
+fn load_gitignore(dir: &Path) -> Option<Gitignore> {
+    let path = dir.join(".gitignore");
+    if !path.is_file() {
+        return None;
+    }
+
+    let mut builder = GitignoreBuilder::new(dir);
+    if builder.add(&path).is_some() {
+        return None;
+    }
+    builder.build().ok()
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum Unit {
+    Binary,
+    Si,
+    Bytes,
+}
+
+fn resolve_unit(cli: &Cli) -> Unit {
+    if cli.raw_bytes {
+        return Unit::Bytes;
+    }
+    if cli.binary {
+        return Unit::Binary;
+    }
+    if cli.si {
+        return Unit::Si;
+    }
+    cli.unit.unwrap_or(Unit::Binary)
+}
+
+fn entry_bytes(meta: &std::fs::Metadata, cli: &Cli) -> u64 {
+    if cli.usage {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            return meta.blocks() * 512;
+        }
+    }
+
+    meta.len()
+}
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+enum SortKey {
+    Name,
+    Size,
+    Ext,
+}
+
+const BAR_WIDTH: usize = 20;
+
+fn render_bar(bytes: u64, total: u64) -> String {
+    if total == 0 {
+        return "░".repeat(BAR_WIDTH);
+    }
+
+    let filled = ((bytes as u128 * BAR_WIDTH as u128) / total as u128) as usize;
+    let filled = filled.min(BAR_WIDTH);
+    format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled))
+}
+
+const DEFAULT_TERM_WIDTH: usize = 80;
+
+fn term_width() -> usize {
+    terminal_size()
+        .map(|(Width(w), _)| w as usize)
+        .unwrap_or(DEFAULT_TERM_WIDTH)
+}
+
+/// Truncates `s` to fit within `max_width` display columns, appending an
+/// ellipsis when it had to cut the text short.
+fn truncate_to_width(s: &str, max_width: usize) -> String {
+    if UnicodeWidthStr::width(s) <= max_width {
+        return s.to_string();
+    }
+
+    const ELLIPSIS: char = '…';
+    if max_width == 0 {
+        return String::new();
+    }
+
+    let budget = max_width.saturating_sub(1);
+    let mut result = String::new();
+    let mut width = 0;
+    for ch in s.chars() {
+        let w = ch.width().unwrap_or(0);
+        if width + w > budget {
+            break;
+        }
+        result.push(ch);
+        width += w;
+    }
+    result.push(ELLIPSIS);
+    result
+}
+
+/// Left-pads `s` with spaces so it occupies exactly `width` display columns,
+/// right-justifying it within that width.
+fn pad_to_width(s: &str, width: usize) -> String {
+    let pad = width.saturating_sub(UnicodeWidthStr::width(s));
+    format!("{}{}", " ".repeat(pad), s)
+}
+
+fn parse_size(s: &str) -> Result<u64, String> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err("size must not be empty".to_string());
+    }
+
+    let (number, multiplier) = match s.chars().last().unwrap().to_ascii_uppercase() {
+        'K' => (&s[..s.len() - 1], BYTES_IN_KB),
+        'M' => (&s[..s.len() - 1], BYTES_IN_MB),
+        'G' => (&s[..s.len() - 1], BYTES_IN_GB),
+        _ => (s, 1.0),
+    };
+
+    number
+        .parse::<f64>()
+        .map(|n| (n * multiplier) as u64)
+        .map_err(|_| format!("invalid size: {}", s))
+}
+
+fn get_symbol(i: usize, length: usize, indent: usize, ascii: bool) -> &'static str {
+    if ascii {
+        if i == 0 {
+            if length == 1 {
+                if indent != 0 {
+                    return "`";
+                }
+                return "-";
+            } else {
+                if indent != 0 {
+                    return "|";
+                }
+                return "+";
+            }
+        } else if i == length - 1 {
+            return "`";
+        };
+
+        return "|";
+    }
+
+    if i == 0 {
+        if length == 1 {
+            if indent != 0 {
+                return "╰";
+            }
+            return "─";
+        } else {
+            if indent != 0 {
+                return "├";
+            }
+            return "╭";
+        }
+    } else if i == length - 1 {
+        return "╰";
+    };
+
+    "├"
+}
+
+const BINARY_LABELS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+const SI_LABELS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+fn beautify_bytes(bytes: u64, unit: Unit) -> String {
+    if let Unit::Bytes = unit {
+        return format!("{}B", bytes);
+    }
+
+    let (base, labels) = match unit {
+        Unit::Binary => (BYTES_IN_KB, BINARY_LABELS),
+        Unit::Si => (1000.0, SI_LABELS),
+        Unit::Bytes => unreachable!(),
+    };
+
+    let mut value = bytes as f64;
+    let mut index = 0;
+    while value >= base && index < labels.len() - 1 {
+        value /= base;
+        index += 1;
+    }
+
+    if index == 0 {
+        format!("{}{}", bytes, labels[index])
+    } else {
+        format!("{:.2}{}", value, labels[index])
+    }
+}
+
+/// A pre-scanned filesystem entry. Directories carry their recursive byte
+/// total and already-scanned children, so rendering never touches the disk.
+struct Node {
+    name: String,
+    bytes: u64,
+    is_dir: bool,
+    is_symlink: bool,
+    is_executable: bool,
+    error: Option<String>,
+    children: Vec<Node>,
+}
+
+fn node_ext(node: &Node) -> String {
+    Path::new(&node.name)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+fn is_executable(meta: &std::fs::Metadata) -> bool {
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        meta.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = meta;
+        false
+    }
+}
+
+/// Resolves the LS_COLORS-driven ANSI code for a node, preferring its
+/// filesystem type over its extension, the way `ls`/dutree colorize.
+fn type_color<'a>(node: &Node, ls_colors: &'a LsColors) -> Option<&'a str> {
+    if node.is_dir {
+        return ls_colors.directory();
+    }
+    if node.is_symlink {
+        return ls_colors.symlink();
+    }
+    if node.is_executable {
+        return ls_colors.executable();
+    }
+
+    let ext = node_ext(node);
+    if ext.is_empty() {
+        None
+    } else {
+        ls_colors.color_for_ext(&ext)
+    }
+}
+
+fn read_entries(
+    path: &Path,
+    cli: &Cli,
+    exclude_set: &GlobSet,
+    gitignores: &[Gitignore],
+) -> std::io::Result<Vec<std::fs::DirEntry>> {
+    Ok(std::fs::read_dir(path)?
+        .filter_map(|r| r.ok())
+        .filter(|r| {
+            let entry_path = r.path();
+
+            let hidden = entry_path
+                .file_name()
+                .is_some_and(|name| name.to_str().is_some_and(|s| s.starts_with(".")));
+            if hidden && !cli.all {
+                return false;
+            }
+
+            if exclude_set.is_match(&entry_path)
+                || entry_path
+                    .file_name()
+                    .is_some_and(|name| exclude_set.is_match(name))
+            {
+                return false;
+            }
+
+            if gitignores
+                .iter()
+                .any(|g| g.matched(&entry_path, entry_path.is_dir()).is_ignore())
+            {
+                return false;
+            }
+
+            true
+        })
+        .collect())
+}
+
+/// Walks `path` and builds its node tree, scanning sibling subdirectories in
+/// parallel on a rayon thread pool so large trees aren't scanned serially.
+///
+/// Recursion here is unbounded by the print `depth`: byte totals must reflect
+/// the *entire* subtree regardless of how much of it `render` ends up
+/// printing.
+fn scan(path: &Path, cli: &Cli, exclude_set: &GlobSet, gitignores: &[Gitignore]) -> Node {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.display().to_string());
+
+    let is_symlink = path
+        .symlink_metadata()
+        .map(|m| m.file_type().is_symlink())
+        .unwrap_or(false);
+
+    if !path.is_dir() {
+        let meta = path.metadata().ok();
+        let bytes = meta.as_ref().map(|m| entry_bytes(m, cli)).unwrap_or(0);
+        let is_executable = meta.as_ref().map(is_executable).unwrap_or(false);
+        return Node {
+            name,
+            bytes,
+            is_dir: false,
+            is_symlink,
+            is_executable,
+            error: None,
+            children: Vec::new(),
+        };
+    }
+
+    let mut gitignores = gitignores.to_vec();
+    if cli.gitignore {
+        if let Some(gi) = load_gitignore(path) {
+            gitignores.push(gi);
+        }
+    }
+
+    let entries = match read_entries(path, cli, exclude_set, &gitignores) {
+        Ok(entries) => entries,
+        Err(e) => {
+            return Node {
+                name,
+                bytes: 0,
+                is_dir: true,
+                is_symlink,
+                is_executable: false,
+                error: Some(e.to_string()),
+                children: Vec::new(),
+            };
+        }
+    };
+
+    let children: Vec<Node> = entries
+        .par_iter()
+        .map(|entry| scan(&entry.path(), cli, exclude_set, &gitignores))
+        .collect();
+
+    let bytes = children.iter().map(|child| child.bytes).sum();
+
+    Node {
+        name,
+        bytes,
+        is_dir: true,
+        is_symlink,
+        is_executable: false,
+        error: None,
+        children,
+    }
+}
+
+/// Renders an already-scanned node's children, applying aggregation,
+/// sorting and bars before printing a single ordered pass.
+fn render(node: &Node, depth: i8, indent: usize, cli: &Cli, ls_colors: &LsColors, color_enabled: bool) {
+    if depth == -1 {
+        return;
+    }
+
+    if let Some(e) = &node.error {
+        let corner = if cli.ascii {
+            "`".to_string()
+        } else {
+            "╰".dimmed().to_string()
+        };
+        println!("{}{} {}", " ".repeat(indent * 2), corner, e);
+        return;
+    }
+
+    let mut shown: Vec<&Node> = Vec::new();
+    let mut aggregated_count: u64 = 0;
+    let mut aggregated_bytes: u64 = 0;
+
+    for child in &node.children {
+        if child.is_dir {
+            shown.push(child);
+            continue;
+        }
+
+        if let Some(threshold) = cli.aggr {
+            if child.bytes < threshold {
+                aggregated_count += 1;
+                aggregated_bytes += child.bytes;
+                continue;
+            }
+        }
+
+        shown.push(child);
+    }
+
+    shown.sort_by(|a, b| {
+        if cli.dirs_first && a.is_dir != b.is_dir {
+            return b.is_dir.cmp(&a.is_dir);
+        }
+
+        let ordering = match cli.sort {
+            Some(SortKey::Size) => a.bytes.cmp(&b.bytes),
+            Some(SortKey::Ext) => node_ext(a).cmp(&node_ext(b)),
+            Some(SortKey::Name) | None => a.name.cmp(&b.name),
+        };
+
+        if cli.reverse {
+            ordering.reverse()
+        } else {
+            ordering
+        }
+    });
+
+    let has_aggregate = aggregated_count > 0;
+    let length = shown.len() + if has_aggregate { 1 } else { 0 };
+    let level_total: u64 = shown.iter().map(|child| child.bytes).sum::<u64>() + aggregated_bytes;
+    let unit = resolve_unit(cli);
+
+    // Plain (uncolored) name and size text for every row at this level, so
+    // the widest one can set this level's aligned size column.
+    let mut plain_names: Vec<String> = shown
+        .iter()
+        .map(|child| {
+            if child.is_dir {
+                format!("{}/", child.name)
+            } else {
+                child.name.clone()
+            }
+        })
+        .collect();
+    let mut size_texts: Vec<String> = shown
+        .iter()
+        .map(|child| {
+            let bar = if cli.bars {
+                format!(" {}", render_bar(child.bytes, level_total))
+            } else {
+                String::new()
+            };
+            format!("{}  {}", bar, beautify_bytes(child.bytes, unit))
+        })
+        .collect();
+    if has_aggregate {
+        plain_names.push(format!("<{} files>", aggregated_count));
+        let bar = if cli.bars {
+            format!(" {}", render_bar(aggregated_bytes, level_total))
+        } else {
+            String::new()
+        };
+        size_texts.push(format!("{}  {}", bar, beautify_bytes(aggregated_bytes, unit)));
+    }
+
+    let prefix_width = indent * 2 + 2;
+    let max_name_width = plain_names
+        .iter()
+        .map(|n| UnicodeWidthStr::width(n.as_str()))
+        .max()
+        .unwrap_or(0);
+    let max_size_width = size_texts
+        .iter()
+        .map(|s| UnicodeWidthStr::width(s.as_str()))
+        .max()
+        .unwrap_or(0);
+    let available = term_width()
+        .saturating_sub(prefix_width + max_size_width)
+        .max(1);
+    let name_column = max_name_width.min(available);
+
+    for (i, child) in shown.iter().enumerate() {
+        let symbol = get_symbol(i, length, indent, cli.ascii);
+        let symbol = if cli.ascii {
+            symbol.to_string()
+        } else {
+            symbol.dimmed().to_string()
+        };
+        let prefix = format!("{}{} ", " ".repeat(indent * 2), symbol);
+
+        let fitted = truncate_to_width(&plain_names[i], name_column);
+        let pad = " ".repeat(name_column.saturating_sub(UnicodeWidthStr::width(fitted.as_str())));
+
+        let color = if color_enabled { type_color(child, ls_colors) } else { None };
+        let display_name = match color {
+            Some(code) => ls_colors::paint(code, &fitted),
+            None if child.is_dir => {
+                if depth == 0 {
+                    fitted.normal().to_string()
+                } else {
+                    fitted.dimmed().to_string()
+                }
+            }
+            None => fitted.clone(),
+        };
+
+        let size_text = pad_to_width(&size_texts[i], max_size_width);
+        println!("{}{}{}{}", prefix, display_name, pad, size_text);
+
+        if child.is_dir {
+            render(child, depth - 1, indent + 1, cli, ls_colors, color_enabled);
+        }
+    }
+
+    if has_aggregate {
+        let symbol = get_symbol(shown.len(), length, indent, cli.ascii);
+        let symbol = if cli.ascii {
+            symbol.to_string()
+        } else {
+            symbol.dimmed().to_string()
+        };
+        let prefix = format!("{}{} ", " ".repeat(indent * 2), symbol);
+
+        let fitted = truncate_to_width(plain_names.last().unwrap(), name_column);
+        let pad = " ".repeat(name_column.saturating_sub(UnicodeWidthStr::width(fitted.as_str())));
+
+        let size_text = pad_to_width(size_texts.last().unwrap(), max_size_width);
+        println!("{}{}{}{}", prefix, fitted, pad, size_text);
+    }
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let path = PathBuf::from(&cli.location);
+
+    if !path.is_dir() {
+        eprintln!("{} is not a directory", path.display());
+        return ExitCode::FAILURE;
+    }
+
+    let mut depth = cli.depth;
+    if depth < 0 {
+        depth = i8::MAX;
+    }
+
+    let color_enabled = !cli.ascii && std::env::var_os("NO_COLOR").is_none();
+    if !color_enabled {
+        colored::control::set_override(false);
+    }
+    let ls_colors = LsColors::from_env();
+
+    let exclude_set = match build_exclude_set(&cli.excludes) {
+        Ok(set) => set,
+        Err(e) => {
+            eprintln!("invalid --exclude pattern: {}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let root = scan(&path, &cli, &exclude_set, &[]);
+    render(&root, depth, 0, &cli, &ls_colors, color_enabled);
+
+    if root.error.is_some() {
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}